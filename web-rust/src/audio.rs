@@ -0,0 +1,66 @@
+use wasm_bindgen::JsValue;
+use web_sys::{AudioContext, OscillatorType};
+
+use crate::game::Sound;
+
+/// A tiny WebAudio sound bank. Rather than ship clips it synthesizes a short
+/// tone per event with an oscillator and a quick gain envelope, and honours a
+/// mute flag toggled from the HUD.
+pub struct Audio {
+    ctx: AudioContext,
+    muted: bool,
+}
+
+impl Audio {
+    pub fn new() -> Result<Self, JsValue> {
+        Ok(Self {
+            ctx: AudioContext::new()?,
+            muted: false,
+        })
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+
+    pub fn toggle_muted(&mut self) -> bool {
+        self.muted = !self.muted;
+        self.muted
+    }
+
+    /// Play the cue for a game event, unless muted.
+    pub fn play(&self, sound: Sound) {
+        if self.muted {
+            return;
+        }
+        let (freq, kind, dur) = match sound {
+            Sound::Flap => (520.0, OscillatorType::Square, 0.08),
+            Sound::Score => (880.0, OscillatorType::Triangle, 0.12),
+            Sound::Death => (140.0, OscillatorType::Sawtooth, 0.35),
+        };
+        // A dropped tone is not worth surfacing to the player.
+        let _ = self.blip(freq, kind, dur);
+    }
+
+    fn blip(&self, freq: f32, kind: OscillatorType, dur: f64) -> Result<(), JsValue> {
+        let osc = self.ctx.create_oscillator()?;
+        let gain = self.ctx.create_gain()?;
+        osc.set_type(kind);
+        osc.frequency().set_value(freq);
+
+        let now = self.ctx.current_time();
+        gain.gain().set_value(0.0001);
+        gain.gain().exponential_ramp_to_value_at_time(0.25, now + 0.01)?;
+        gain.gain().exponential_ramp_to_value_at_time(0.0001, now + dur)?;
+
+        osc.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&self.ctx.destination())?;
+        osc.start()?;
+        osc.stop_with_when(now + dur)?;
+        Ok(())
+    }
+}
@@ -1,11 +1,30 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
-use web_sys::{Document, HtmlDivElement};
+use web_sys::{Document, HtmlButtonElement, HtmlDivElement};
+
+/// A control-panel action the player clicked. The host drains these each frame
+/// and applies them to its [`crate::game::SimClock`] / game.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ControlCommand {
+    TogglePause,
+    StepOnce,
+    CycleSpeed,
+    Restart,
+    ToggleMute,
+}
 
 pub struct Hud {
     root: HtmlDivElement,
     fps: HtmlDivElement,
     score: HtmlDivElement,
     status: HtmlDivElement,
+    pause: HtmlButtonElement,
+    speed: HtmlButtonElement,
+    mute: HtmlButtonElement,
+    commands: Rc<RefCell<Vec<ControlCommand>>>,
 }
 
 impl Hud {
@@ -39,6 +58,33 @@ impl Hud {
         root.append_child(&fps)?;
         root.append_child(&score)?;
         root.append_child(&status)?;
+
+        // Clickable control panel. The root is pointer-events:none so the
+        // canvas keeps the taps, so the buttons opt back in individually.
+        let controls: HtmlDivElement = document.create_element("div")?.dyn_into()?;
+        set_style(
+            &controls,
+            "margin-top:10px;display:flex;gap:6px;pointer-events:auto;",
+        );
+
+        let commands: Rc<RefCell<Vec<ControlCommand>>> = Rc::new(RefCell::new(Vec::new()));
+        let pause = make_button(document, "Pause")?;
+        let step = make_button(document, "Step")?;
+        let speed = make_button(document, "1×")?;
+        let restart = make_button(document, "Restart")?;
+        let mute = make_button(document, "Mute")?;
+        wire_button(&pause, &commands, ControlCommand::TogglePause);
+        wire_button(&step, &commands, ControlCommand::StepOnce);
+        wire_button(&speed, &commands, ControlCommand::CycleSpeed);
+        wire_button(&restart, &commands, ControlCommand::Restart);
+        wire_button(&mute, &commands, ControlCommand::ToggleMute);
+        controls.append_child(&pause)?;
+        controls.append_child(&step)?;
+        controls.append_child(&speed)?;
+        controls.append_child(&restart)?;
+        controls.append_child(&mute)?;
+        root.append_child(&controls)?;
+
         body.append_child(&root)?;
 
         Ok(Self {
@@ -46,9 +92,31 @@ impl Hud {
             fps,
             score,
             status,
+            pause,
+            speed,
+            mute,
+            commands,
         })
     }
 
+    /// Drain the commands queued by button clicks since the last call.
+    pub fn drain_commands(&self) -> Vec<ControlCommand> {
+        self.commands.borrow_mut().drain(..).collect()
+    }
+
+    /// Reflect the simulation clock's state back onto the button labels.
+    pub fn set_sim_state(&self, paused: bool, speed: u32) {
+        self.pause
+            .set_inner_text(if paused { "Play" } else { "Pause" });
+        self.speed.set_inner_text(&format!("{}×", speed));
+    }
+
+    /// Reflect the audio mute state back onto the mute button label.
+    pub fn set_muted(&self, muted: bool) {
+        self.mute
+            .set_inner_text(if muted { "Unmute" } else { "Mute" });
+    }
+
     pub fn set_fps(&self, fps: f32) {
         self.fps
             .set_inner_text(&format!("FPS: {:>3.0}", fps.round().clamp(0.0, 999.0)));
@@ -94,3 +162,30 @@ impl Hud {
 fn set_style(element: &HtmlDivElement, css: &str) {
     element.style().set_css_text(css);
 }
+
+fn make_button(
+    document: &Document,
+    label: &str,
+) -> Result<HtmlButtonElement, wasm_bindgen::JsValue> {
+    let button: HtmlButtonElement = document.create_element("button")?.dyn_into()?;
+    button.set_inner_text(label);
+    button.style().set_css_text(
+        "pointer-events:auto;cursor:pointer;font-family:'Inter',sans-serif;font-size:13px;\
+         color:white;background:rgba(0,0,0,0.45);border:1px solid rgba(255,255,255,0.35);\
+         border-radius:6px;padding:4px 8px;",
+    );
+    Ok(button)
+}
+
+fn wire_button(
+    button: &HtmlButtonElement,
+    commands: &Rc<RefCell<Vec<ControlCommand>>>,
+    command: ControlCommand,
+) {
+    let queue = commands.clone();
+    let closure = Closure::wrap(Box::new(move |_: web_sys::MouseEvent| {
+        queue.borrow_mut().push(command);
+    }) as Box<dyn FnMut(_)>);
+    button.set_onclick(Some(closure.as_ref().unchecked_ref()));
+    closure.forget();
+}
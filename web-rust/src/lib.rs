@@ -2,29 +2,73 @@ use std::{cell::RefCell, rc::Rc};
 
 use anyhow::{anyhow, Result};
 use bytemuck::{Pod, Zeroable};
-use js_sys::Function;
 use log::error;
-use wasm_bindgen::{prelude::*, JsCast};
+use wgpu::util::DeviceExt;
+
+#[cfg(target_arch = "wasm32")]
+use js_sys::Function;
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::closure::Closure;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{prelude::*, JsCast};
+#[cfg(target_arch = "wasm32")]
 use web_sys::{self, window, HtmlCanvasElement, HtmlDivElement};
-use wgpu::util::DeviceExt;
+
+#[cfg(not(target_arch = "wasm32"))]
+use winit::{
+    event::{ElementState, Event, KeyEvent, MouseButton, Touch, TouchPhase, WindowEvent},
+    event_loop::{ControlFlow, EventLoop, EventLoopBuilder},
+    keyboard::{KeyCode, PhysicalKey},
+    window::WindowBuilder,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use accesskit::{Node, NodeId, Role, Tree, TreeUpdate};
+#[cfg(not(target_arch = "wasm32"))]
+use accesskit_winit::{ActionRequestEvent, Adapter as AccessKitAdapter};
+
+#[cfg(target_arch = "wasm32")]
+mod audio;
+mod game;
+#[cfg(target_arch = "wasm32")]
+mod hud;
+mod tuning;
 
 const WORLD_WIDTH: f32 = 288.0;
 const WORLD_HEIGHT: f32 = 512.0;
 const PIPE_GAP: f32 = 120.0;
+/// Default pipe spacing, used when the tuning's `pipe_spacing` is left at `0.0`.
 const PIPE_SPACING: f32 = 220.0;
-const PIPE_SPEED: f32 = 120.0;
 const PIPE_WIDTH: f32 = 52.0;
 const BIRD_X: f32 = 72.0;
-const GRAVITY: f32 = 900.0;
-const FLAP_VELOCITY: f32 = -320.0;
 const STEP: f32 = 1.0 / 120.0;
 const MAX_FALL_SPEED: f32 = 500.0;
 const MAX_RISE_SPEED: f32 = -480.0;
-const PIPE_MIN_Y: f32 = 160.0;
-const PIPE_MAX_Y: f32 = 360.0;
 const GROUND_HEIGHT: f32 = 100.0;
 
+/// Sink for the single line of HUD text the frame loop produces.
+///
+/// The browser build writes into the `#hud` overlay element; the native
+/// build has no DOM, so it routes the same text through `log`.
+enum Hud {
+    #[cfg(target_arch = "wasm32")]
+    Web(HtmlDivElement),
+    #[cfg(not(target_arch = "wasm32"))]
+    Console,
+}
+
+impl Hud {
+    fn set_text(&self, text: &str) {
+        match self {
+            #[cfg(target_arch = "wasm32")]
+            Hud::Web(element) => element.set_inner_text(text),
+            #[cfg(not(target_arch = "wasm32"))]
+            Hud::Console => log::info!("{}", text.replace('\n', " | ")),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen(start)]
 pub async fn start() -> Result<(), JsValue> {
     console_error_panic_hook::set_once();
@@ -49,7 +93,7 @@ pub async fn start() -> Result<(), JsValue> {
     install_trigger_jump(&window, &jump_flag)?;
     install_input_listeners(&window, &canvas, &jump_flag)?;
 
-    match run(canvas, hud.clone(), jump_flag.clone()).await {
+    match run(canvas, Hud::Web(hud.clone()), jump_flag.clone()).await {
         Ok(()) => Ok(()),
         Err(err) => {
             error!("{:#}", err);
@@ -59,6 +103,7 @@ pub async fn start() -> Result<(), JsValue> {
     }
 }
 
+#[cfg(target_arch = "wasm32")]
 fn install_trigger_jump(window: &web_sys::Window, jump_flag: &Rc<RefCell<bool>>) -> Result<(), JsValue> {
     let flag = jump_flag.clone();
     let closure = Closure::wrap(Box::new(move || {
@@ -71,6 +116,7 @@ fn install_trigger_jump(window: &web_sys::Window, jump_flag: &Rc<RefCell<bool>>)
     Ok(())
 }
 
+#[cfg(target_arch = "wasm32")]
 fn install_input_listeners(
     window: &web_sys::Window,
     canvas: &HtmlCanvasElement,
@@ -110,30 +156,136 @@ fn install_input_listeners(
     Ok(())
 }
 
-async fn run(canvas: HtmlCanvasElement, hud: HtmlDivElement, jump_flag: Rc<RefCell<bool>>) -> Result<()> {
-    let instance = wgpu::Instance::default();
+/// Expose `window.exportBrain()` / `window.loadBrain(json)` so the page can
+/// persist the current best champion to `localStorage` and reload it later,
+/// mirroring how [`install_trigger_jump`] exposes `triggerJump`.
+#[cfg(target_arch = "wasm32")]
+fn install_brain_controls(window: &web_sys::Window, state: &Rc<RefCell<AppState>>) -> Result<(), JsValue> {
+    let export_state = state.clone();
+    let export_closure = Closure::wrap(Box::new(move || -> String {
+        let json = export_state.borrow().game.export_best_brain();
+        if let Some(store) = local_storage() {
+            let _ = store.set_item(BRAIN_STORAGE_KEY, &json);
+        }
+        json
+    }) as Box<dyn Fn() -> String>);
+    let export_func: &Function = export_closure.as_ref().unchecked_ref();
+    js_sys::Reflect::set(window, &JsValue::from_str("exportBrain"), export_func)?;
+    export_closure.forget();
+
+    let load_state = state.clone();
+    let load_closure = Closure::wrap(Box::new(move |json: String| -> bool {
+        load_state.borrow_mut().game.load_brain(&json)
+    }) as Box<dyn Fn(String) -> bool>);
+    let load_func: &Function = load_closure.as_ref().unchecked_ref();
+    js_sys::Reflect::set(window, &JsValue::from_str("loadBrain"), load_func)?;
+    load_closure.forget();
+
+    Ok(())
+}
+
+/// `localStorage` key the best-trained brain is persisted under.
+#[cfg(target_arch = "wasm32")]
+const BRAIN_STORAGE_KEY: &str = "flappy-nerd.brain";
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// `localStorage` key the best replay (score, seed, flap ticks) is persisted
+/// under, so the next load can race it as a ghost.
+#[cfg(target_arch = "wasm32")]
+const REPLAY_STORAGE_KEY: &str = "flappy-nerd.best-replay";
+
+/// Persist `game`'s run as the new best replay if it beat the one already in
+/// `localStorage`. Called once on the death edge in [`AppState::frame`].
+#[cfg(target_arch = "wasm32")]
+fn save_best_replay(game: &Game) {
+    let Some(store) = local_storage() else {
+        return;
+    };
+    let previous_best = store
+        .get_item(REPLAY_STORAGE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|raw| decode_replay(&raw))
+        .map(|(score, _, _)| score)
+        .unwrap_or(i32::MIN);
+    if game.score <= previous_best {
+        return;
+    }
+    let (seed, flaps) = game.replay_record();
+    let _ = store.set_item(REPLAY_STORAGE_KEY, &encode_replay(game.score, seed, &flaps));
+}
+
+/// Load the best replay from `localStorage`, if any, so a fresh game can race
+/// it as a ghost from the start.
+#[cfg(target_arch = "wasm32")]
+fn load_best_replay() -> Option<(u64, Vec<u32>)> {
+    let store = local_storage()?;
+    let raw = store.get_item(REPLAY_STORAGE_KEY).ok().flatten()?;
+    decode_replay(&raw).map(|(_, seed, flaps)| (seed, flaps))
+}
+
+/// `localStorage` key an optional Rhai difficulty preset lives under. Players
+/// can drop a custom script here to ship their own difficulty curve. There is
+/// no in-app editor, so this is read-only from the game's side: nothing ever
+/// writes this key back, unlike `BEST_SCORE_STORAGE_KEY`/`BG_COLOR_STORAGE_KEY`
+/// below, which the app itself updates. Likewise there is no `Palette` the
+/// player can customize (its colors/speeds are compiled in), so there is
+/// nothing to persist for it either.
+#[cfg(target_arch = "wasm32")]
+const TUNING_STORAGE_KEY: &str = "flappy-nerd.tuning";
+
+/// Load a Rhai tuning script from `localStorage`, if one has been saved.
+/// Falls back to the defaults on a missing key or a script error.
+#[cfg(target_arch = "wasm32")]
+fn load_tuning() -> Option<tuning::Tuning> {
+    let src = local_storage()?.get_item(TUNING_STORAGE_KEY).ok().flatten()?;
+    match tuning::Tuning::from_script(&src) {
+        Ok(tuning) => Some(tuning),
+        Err(err) => {
+            log::warn!("ignoring tuning script: {err}");
+            None
+        }
+    }
+}
+
+/// Encode as `score;seed;flap,flap,...`.
+#[cfg(target_arch = "wasm32")]
+fn encode_replay(score: i32, seed: u64, flaps: &[u32]) -> String {
+    let flaps = flaps.iter().map(u32::to_string).collect::<Vec<_>>().join(",");
+    format!("{score};{seed};{flaps}")
+}
+
+#[cfg(target_arch = "wasm32")]
+fn decode_replay(raw: &str) -> Option<(i32, u64, Vec<u32>)> {
+    let mut parts = raw.splitn(3, ';');
+    let score = parts.next()?.parse().ok()?;
+    let seed = parts.next()?.parse().ok()?;
+    let flaps = parts
+        .next()?
+        .split(',')
+        .filter(|token| !token.is_empty())
+        .map(|token| token.parse().ok())
+        .collect::<Option<Vec<u32>>>()?;
+    Some((score, seed, flaps))
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn run(canvas: HtmlCanvasElement, hud: Hud, jump_flag: Rc<RefCell<bool>>) -> Result<()> {
+    // Expose both backends so we can prefer WebGPU and fall back to WebGL2 on
+    // browsers that lack it (Firefox, older Safari). The render pipeline sticks
+    // to downlevel_webgl2_defaults() limits so either backend works.
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::Backends::BROWSER_WEBGPU | wgpu::Backends::GL,
+        ..Default::default()
+    });
     let surface = instance.create_surface(wgpu::SurfaceTarget::Canvas(canvas.clone()))?;
 
-    let adapter = instance
-        .request_adapter(&wgpu::RequestAdapterOptions {
-            power_preference: wgpu::PowerPreference::HighPerformance,
-            compatible_surface: Some(&surface),
-            force_fallback_adapter: false,
-        })
-        .await
-        .ok_or_else(|| anyhow!("WebGPU adapter not available"))?;
-
-    let (device, queue) = adapter
-        .request_device(
-            &wgpu::DeviceDescriptor {
-                label: Some("device"),
-                required_features: wgpu::Features::empty(),
-                required_limits: wgpu::Limits::downlevel_webgl2_defaults(),
-            },
-            None,
-        )
-        .await
-        .map_err(|e| anyhow!("Request device failed: {}", e))?;
+    let (adapter, device, queue) = acquire_adapter_device(&instance, &surface).await?;
+    let backend = backend_label(adapter.get_info().backend);
 
     let (width, height) = canvas_size(&canvas);
     let surface_caps = surface.get_capabilities(&adapter);
@@ -168,9 +320,36 @@ async fn run(canvas: HtmlCanvasElement, hud: HtmlDivElement, jump_flag: Rc<RefCe
     };
     surface.configure(&device, &config);
 
-    let renderer = Renderer::new(&device, surface_format)?;
-    let game = Game::new();
+    let renderer = Renderer::new(&device, &queue, surface_format)?;
+    let mut game = if learning_requested() {
+        Game::new_learning(POPULATION_SIZE)
+    } else {
+        Game::new()
+    };
+    if let Some(tuning) = load_tuning() {
+        game.set_tuning(tuning);
+    }
+    if game.learning() {
+        if let Some(json) = local_storage().and_then(|store| store.get_item(BRAIN_STORAGE_KEY).ok().flatten()) {
+            game.load_brain(&json);
+        }
+    } else if let Some((seed, flaps)) = load_best_replay() {
+        // Race the best recorded run as a ghost from the very first attempt.
+        game.start_replay(seed, flaps);
+    }
+    // Headless autopilot training happens once, up front, against the live
+    // game's own seed; the trained genome then flies that same game.
+    let autopilot = if autopilot_requested() && !game.learning() {
+        Some(Autopilot::train(game.seed()))
+    } else {
+        None
+    };
     let timer = FrameTimer::default();
+    let a11y = Announcer::new(create_live_region()?);
+    let controls = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| anyhow!("No document"))
+        .and_then(|document| hud::Hud::new(&document).map_err(|err| anyhow!("{err:?}")))?;
 
     let state = Rc::new(RefCell::new(AppState {
         surface,
@@ -184,15 +363,78 @@ async fn run(canvas: HtmlCanvasElement, hud: HtmlDivElement, jump_flag: Rc<RefCe
         canvas,
         timer,
         bg_color: parse_bg_color(),
+        backend,
+        a11y,
         raf_closure: None,
         pending_jump: false,
+        was_dead: false,
+        sim_clock: game::SimClock::new(),
+        controls,
+        muted: false,
+        best_score: load_best_score(),
+        audio: audio::Audio::new().ok(),
+        autopilot,
     }));
 
+    if let Some(window) = web_sys::window() {
+        install_brain_controls(&window, &state)?;
+    }
+
     start_animation_loop(state)?;
 
     Ok(())
 }
 
+/// Human-readable label for the chosen graphics backend, shown in the HUD.
+fn backend_label(backend: wgpu::Backend) -> &'static str {
+    match backend {
+        wgpu::Backend::BrowserWebGpu => "WebGPU",
+        wgpu::Backend::Gl => "WebGL2",
+        wgpu::Backend::Vulkan => "Vulkan",
+        wgpu::Backend::Metal => "Metal",
+        wgpu::Backend::Dx12 => "DX12",
+        _ => "GPU",
+    }
+}
+
+/// Acquire an adapter and device, preferring WebGPU and falling back to the
+/// WebGL2 backend before giving up. Mirrors the retry the learn-wgpu samples
+/// use to stay runnable on browsers without WebGPU.
+#[cfg(target_arch = "wasm32")]
+async fn acquire_adapter_device(
+    instance: &wgpu::Instance,
+    surface: &wgpu::Surface<'static>,
+) -> Result<(wgpu::Adapter, wgpu::Device, wgpu::Queue)> {
+    for force_fallback in [false, true] {
+        let Some(adapter) = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: Some(surface),
+                force_fallback_adapter: force_fallback,
+            })
+            .await
+        else {
+            continue;
+        };
+        match adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("device"),
+                    required_features: wgpu::Features::empty(),
+                    required_limits: wgpu::Limits::downlevel_webgl2_defaults(),
+                },
+                None,
+            )
+            .await
+        {
+            Ok((device, queue)) => return Ok((adapter, device, queue)),
+            Err(err) => error!("Device request failed on {:?}: {err}", adapter.get_info().backend),
+        }
+    }
+    Err(anyhow!("Neither WebGPU nor WebGL2 is available"))
+}
+
+#[cfg(target_arch = "wasm32")]
 fn start_animation_loop(state: Rc<RefCell<AppState>>) -> Result<()> {
     let win = window().ok_or_else(|| anyhow!("No window"))?;
     let performance = win.performance().ok_or_else(|| anyhow!("No performance"))?;
@@ -215,7 +457,7 @@ fn start_animation_loop(state: Rc<RefCell<AppState>>) -> Result<()> {
                 error!("Frame error: {err:#}");
                 state
                     .hud
-                    .set_inner_text(&format!("WebGPU error\n{err:#}"));
+                    .set_text(&format!("WebGPU error\n{err:#}"));
                 state.raf_closure = None;
                 return;
             }
@@ -258,6 +500,7 @@ fn start_animation_loop(state: Rc<RefCell<AppState>>) -> Result<()> {
     Ok(())
 }
 
+#[cfg(target_arch = "wasm32")]
 fn canvas_size(canvas: &HtmlCanvasElement) -> (u32, u32) {
     let width = canvas.client_width().max(1) as u32;
     let height = canvas.client_height().max(1) as u32;
@@ -271,13 +514,39 @@ struct AppState {
     queue: wgpu::Queue,
     renderer: Renderer,
     game: Game,
-    hud: HtmlDivElement,
+    hud: Hud,
     jump_flag: Rc<RefCell<bool>>,
+    #[cfg(target_arch = "wasm32")]
     canvas: HtmlCanvasElement,
     timer: FrameTimer,
     bg_color: [f32; 3],
+    backend: &'static str,
+    a11y: Announcer,
+    #[cfg(target_arch = "wasm32")]
     raf_closure: Option<Closure<dyn FnMut(f64)>>,
     pending_jump: bool,
+    /// `game.is_dead` as of the previous frame, so [`AppState::frame`] can spot
+    /// the death edge and persist a new best replay exactly once per run.
+    was_dead: bool,
+    /// Turns wall-clock time into fixed-step ticks, honouring the pause/step/
+    /// speed controls from the HUD's control panel.
+    sim_clock: game::SimClock,
+    /// Clickable pause/step/speed/restart/mute panel. Native builds have no
+    /// DOM, so the panel (and the mute state it drives) is web-only.
+    #[cfg(target_arch = "wasm32")]
+    controls: hud::Hud,
+    #[cfg(target_arch = "wasm32")]
+    muted: bool,
+    /// Highest score reached so far, persisted to `localStorage` on web so it
+    /// survives a reload. Always `0` on native, which has nowhere to persist it.
+    best_score: i32,
+    /// `None` when the browser refused to hand out an `AudioContext` (e.g. no
+    /// user gesture yet); sound is simply skipped in that case.
+    #[cfg(target_arch = "wasm32")]
+    audio: Option<audio::Audio>,
+    /// A trained genome flying the game in place of the player, when
+    /// `?autopilot=1` requested one. `None` drives from `jump_flag` as usual.
+    autopilot: Option<Autopilot>,
 }
 
 impl AppState {
@@ -293,17 +562,76 @@ impl AppState {
             self.pending_jump = true;
         }
 
+        #[cfg(target_arch = "wasm32")]
+        for command in self.controls.drain_commands() {
+            match command {
+                hud::ControlCommand::TogglePause => self.sim_clock.toggle_pause(),
+                hud::ControlCommand::StepOnce => self.sim_clock.request_step(),
+                hud::ControlCommand::CycleSpeed => self.sim_clock.cycle_speed(),
+                hud::ControlCommand::Restart => self.game.reset(),
+                hud::ControlCommand::ToggleMute => {
+                    self.muted = !self.muted;
+                    self.controls.set_muted(self.muted);
+                    if let Some(audio) = self.audio.as_mut() {
+                        audio.set_muted(self.muted);
+                    }
+                }
+            }
+        }
+
         self.timer.accumulate(dt);
 
-        while self.timer.accumulator >= STEP {
-            let jump_now = self.pending_jump;
+        for _ in 0..self.sim_clock.ticks(dt) {
+            let jump_now = match &self.autopilot {
+                Some(autopilot) => autopilot.decide(&self.game),
+                None => self.pending_jump,
+            };
+            #[cfg(target_arch = "wasm32")]
+            let prev_score = self.game.score;
+            #[cfg(target_arch = "wasm32")]
+            let was_dead_this_tick = self.game.is_dead;
             self.game.step(STEP, jump_now);
             self.pending_jump = false;
-            self.timer.accumulator -= STEP;
+
+            #[cfg(target_arch = "wasm32")]
+            if let Some(audio) = &self.audio {
+                if jump_now {
+                    audio.play(game::Sound::Flap);
+                }
+                if self.game.score > prev_score {
+                    audio.play(game::Sound::Score);
+                }
+                if self.game.is_dead && !was_dead_this_tick {
+                    audio.play(game::Sound::Death);
+                }
+            }
+        }
+
+        self.timer
+            .update_hud(&self.hud, self.backend, self.game.score, self.game.is_dead);
+        self.a11y.update(self.game.score, self.game.is_dead);
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.controls.set_fps(self.timer.fps);
+            self.controls
+                .set_score(self.game.score.max(0) as u32, self.best_score.max(0) as u32);
+            self.controls
+                .set_sim_state(self.sim_clock.is_paused(), self.sim_clock.speed());
         }
 
-        self.timer.update_hud(&self.hud, self.game.score, self.game.is_dead);
+        if self.game.is_dead && !self.was_dead {
+            if self.game.score > self.best_score {
+                self.best_score = self.game.score;
+                #[cfg(target_arch = "wasm32")]
+                save_best_score(self.best_score);
+            }
+            #[cfg(target_arch = "wasm32")]
+            save_best_replay(&self.game);
+        }
+        self.was_dead = self.game.is_dead;
 
+        #[cfg(target_arch = "wasm32")]
         self.resize_if_needed()?;
 
         let instances = self.game.instance_data(self.config.width, self.config.height);
@@ -331,6 +659,7 @@ impl AppState {
         Ok(())
     }
 
+    #[cfg(target_arch = "wasm32")]
     fn resize_if_needed(&mut self) -> Result<()> {
         let (width, height) = canvas_size(&self.canvas);
         if width > 0 && height > 0 && (width != self.config.width || height != self.config.height) {
@@ -340,49 +669,350 @@ impl AppState {
         }
         Ok(())
     }
+
+    /// Reconfigure the surface to a new physical size. Used by the native
+    /// `winit` backend in response to window resize events; the browser build
+    /// polls the canvas instead through [`AppState::resize_if_needed`].
+    #[cfg(not(target_arch = "wasm32"))]
+    fn resize(&mut self, width: u32, height: u32) {
+        if width > 0 && height > 0 && (width != self.config.width || height != self.config.height) {
+            self.config.width = width;
+            self.config.height = height;
+            self.surface.configure(&self.device, &self.config);
+        }
+    }
+}
+
+/// Create the visually-hidden `aria-live=polite` status region the
+/// [`Announcer`] writes score and game-state transitions into.
+#[cfg(target_arch = "wasm32")]
+fn create_live_region() -> Result<HtmlDivElement> {
+    let document = window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| anyhow!("No document"))?;
+    let element: HtmlDivElement = document
+        .create_element("div")
+        .map_err(|_| anyhow!("Failed to create live region"))?
+        .dyn_into()
+        .map_err(|_| anyhow!("Failed to create live region"))?;
+    element.set_id("a11y-status");
+    let _ = element.set_attribute("role", "status");
+    let _ = element.set_attribute("aria-live", "polite");
+    // Keep it off-screen but still announced by screen readers.
+    element.style().set_css_text(
+        "position:absolute;width:1px;height:1px;overflow:hidden;clip:rect(0 0 0 0);white-space:nowrap;",
+    );
+    if let Some(body) = document.body() {
+        let _ = body.append_child(&element);
+    }
+    Ok(element)
 }
 
+/// `localStorage` key the chosen background colour is persisted under, so a
+/// `?bg=` pick from one visit sticks on the next.
+#[cfg(target_arch = "wasm32")]
+const BG_COLOR_STORAGE_KEY: &str = "flappy-nerd.bg-color";
+
+/// Read the `?bg=RRGGBB` query flag if present (persisting it for next time),
+/// otherwise fall back to a previously persisted colour, otherwise the
+/// default sky blue.
+#[cfg(target_arch = "wasm32")]
 fn parse_bg_color() -> [f32; 3] {
-    if let Some(window) = web_sys::window() {
-        if let Ok(query) = window.location().search() {
-            if let Some(pos) = query.find("bg=") {
-                let value = &query[pos + 3..];
-                let hex = value.split('&').next().unwrap_or("");
-                if hex.len() >= 6 {
-                    if let (Ok(r), Ok(g), Ok(b)) = (
-                        u8::from_str_radix(&hex[0..2], 16),
-                        u8::from_str_radix(&hex[2..4], 16),
-                        u8::from_str_radix(&hex[4..6], 16),
-                    ) {
-                        return [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0];
-                    }
-                }
+    if let Some(hex) = bg_hex_from_query() {
+        if let Some(color) = decode_hex_color(&hex) {
+            if let Some(store) = local_storage() {
+                let _ = store.set_item(BG_COLOR_STORAGE_KEY, &hex);
             }
+            return color;
+        }
+    }
+    if let Some(hex) = local_storage().and_then(|store| store.get_item(BG_COLOR_STORAGE_KEY).ok().flatten()) {
+        if let Some(color) = decode_hex_color(&hex) {
+            return color;
         }
     }
     [0.36, 0.72, 0.92]
 }
 
+#[cfg(target_arch = "wasm32")]
+fn bg_hex_from_query() -> Option<String> {
+    let window = web_sys::window()?;
+    let query = window.location().search().ok()?;
+    let pos = query.find("bg=")?;
+    let value = &query[pos + 3..];
+    Some(value.split('&').next().unwrap_or("").to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn decode_hex_color(hex: &str) -> Option<[f32; 3]> {
+    if hex.len() < 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some([r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0])
+}
+
+/// `localStorage` key the best score reached in player mode is persisted
+/// under.
+#[cfg(target_arch = "wasm32")]
+const BEST_SCORE_STORAGE_KEY: &str = "flappy-nerd.best-score";
+
+#[cfg(target_arch = "wasm32")]
+fn load_best_score() -> i32 {
+    local_storage()
+        .and_then(|store| store.get_item(BEST_SCORE_STORAGE_KEY).ok().flatten())
+        .and_then(|raw| raw.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_best_score(score: i32) {
+    if let Some(store) = local_storage() {
+        let _ = store.set_item(BEST_SCORE_STORAGE_KEY, &score.to_string());
+    }
+}
+
+/// Publishes score and game-state changes to assistive technology. On the web
+/// this drives an `aria-live=polite` status region; the native backend routes
+/// the same transitions through the `accesskit_winit` adapter attached to the
+/// event loop. Announcements fire only on transitions (score delta or death
+/// edge) so screen readers aren't spammed every frame.
+struct Announcer {
+    last_score: i32,
+    was_dead: bool,
+    #[cfg(target_arch = "wasm32")]
+    live: HtmlDivElement,
+    #[cfg(not(target_arch = "wasm32"))]
+    adapter: AccessKitAdapter,
+}
+
+impl Announcer {
+    #[cfg(target_arch = "wasm32")]
+    fn new(live: HtmlDivElement) -> Self {
+        Self {
+            last_score: 0,
+            was_dead: false,
+            live,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn new(adapter: AccessKitAdapter) -> Self {
+        Self {
+            last_score: 0,
+            was_dead: false,
+            adapter,
+        }
+    }
+
+    /// Announce score and life-state transitions. Called alongside the visual
+    /// HUD update each frame.
+    fn update(&mut self, score: i32, dead: bool) {
+        if dead != self.was_dead {
+            self.announce(if dead { "Game over" } else { "New game" });
+            self.was_dead = dead;
+        }
+        if score != self.last_score {
+            self.announce(&format!("Score: {score}"));
+            self.last_score = score;
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn announce(&self, message: &str) {
+        self.live.set_inner_text(message);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn announce(&mut self, message: &str) {
+        let message = message.to_string();
+        self.adapter
+            .update_if_active(move || accessibility_tree(&message));
+    }
+
+    /// Forward an action request the platform screen reader sent back (e.g. a
+    /// focus request) to the accesskit adapter. The status node here doesn't
+    /// expose any actions, so this just keeps the adapter's internal state
+    /// in sync.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn process_action_request(&mut self, event: ActionRequestEvent) {
+        self.adapter.process_action(event.request);
+    }
+}
+
+/// Root window node and the live status node beneath it, for the
+/// `accesskit_winit` tree published on every announcement.
+#[cfg(not(target_arch = "wasm32"))]
+const A11Y_WINDOW_ID: NodeId = NodeId(0);
+#[cfg(not(target_arch = "wasm32"))]
+const A11Y_STATUS_ID: NodeId = NodeId(1);
+
+/// Build the accessibility tree update announcing `message` through the
+/// status node, for [`Announcer::announce`] on the native backend.
+#[cfg(not(target_arch = "wasm32"))]
+fn accessibility_tree(message: &str) -> TreeUpdate {
+    let mut status = Node::new(Role::Status);
+    status.set_value(message.to_string());
+
+    let mut window = Node::new(Role::Window);
+    window.set_children(vec![A11Y_STATUS_ID]);
+
+    TreeUpdate {
+        nodes: vec![(A11Y_WINDOW_ID, window), (A11Y_STATUS_ID, status)],
+        tree: Some(Tree::new(A11Y_WINDOW_ID)),
+        focus: A11Y_STATUS_ID,
+    }
+}
+
+/// Native (desktop / Android) entry point. Mirrors [`start`] but drives the
+/// shared `Game`/`Renderer`/`FrameTimer` from a `winit` event loop instead of
+/// the browser's `requestAnimationFrame`, translating keyboard/mouse/touch
+/// input into the same `jump_flag` the web build feeds.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_native() -> Result<()> {
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    // accesskit_winit delivers action requests from the platform screen reader
+    // back through the winit event loop, so the loop's user event is fixed to
+    // its `ActionRequestEvent` rather than `()`.
+    let event_loop: EventLoop<ActionRequestEvent> =
+        EventLoopBuilder::with_user_event().build()?;
+    let window = Arc::new(
+        WindowBuilder::new()
+            .with_title("Flappy Nerd")
+            .build(&event_loop)?,
+    );
+    let a11y_adapter = AccessKitAdapter::with_event_loop_proxy(
+        &event_loop,
+        window.as_ref(),
+        event_loop.create_proxy(),
+    );
+
+    let instance = wgpu::Instance::default();
+    let surface = instance.create_surface(window.clone())?;
+
+    let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+        power_preference: wgpu::PowerPreference::HighPerformance,
+        compatible_surface: Some(&surface),
+        force_fallback_adapter: false,
+    }))
+    .ok_or_else(|| anyhow!("No suitable GPU adapter"))?;
+
+    let (device, queue) = pollster::block_on(adapter.request_device(
+        &wgpu::DeviceDescriptor {
+            label: Some("device"),
+            required_features: wgpu::Features::empty(),
+            required_limits: wgpu::Limits::downlevel_webgl2_defaults(),
+        },
+        None,
+    ))
+    .map_err(|e| anyhow!("Request device failed: {}", e))?;
+
+    let size = window.inner_size();
+    let surface_caps = surface.get_capabilities(&adapter);
+    let surface_format = surface_caps
+        .formats
+        .iter()
+        .find(|format| format.is_srgb())
+        .copied()
+        .unwrap_or(surface_caps.formats[0]);
+    let config = wgpu::SurfaceConfiguration {
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        format: surface_format,
+        width: size.width.max(1),
+        height: size.height.max(1),
+        present_mode: wgpu::PresentMode::Fifo,
+        desired_maximum_frame_latency: 1,
+        alpha_mode: wgpu::CompositeAlphaMode::Opaque,
+        view_formats: vec![],
+    };
+    surface.configure(&device, &config);
+
+    let renderer = Renderer::new(&device, &queue, surface_format)?;
+    let jump_flag = Rc::new(RefCell::new(false));
+    let mut state = AppState {
+        surface,
+        config,
+        device,
+        queue,
+        renderer,
+        game: Game::new(),
+        hud: Hud::Console,
+        jump_flag: jump_flag.clone(),
+        timer: FrameTimer::default(),
+        bg_color: [0.36, 0.72, 0.92],
+        backend: backend_label(adapter.get_info().backend),
+        a11y: Announcer::new(a11y_adapter),
+        pending_jump: false,
+        was_dead: false,
+        sim_clock: game::SimClock::new(),
+        best_score: 0,
+        autopilot: None,
+    };
+
+    let mut last_time = Instant::now();
+    event_loop.set_control_flow(ControlFlow::Poll);
+    event_loop.run(move |event, target| match event {
+        Event::UserEvent(action_request) => state.a11y.process_action_request(action_request),
+        Event::WindowEvent { event, .. } => match event {
+            WindowEvent::CloseRequested => target.exit(),
+            WindowEvent::Resized(size) => state.resize(size.width, size.height),
+            WindowEvent::KeyboardInput {
+                event:
+                    KeyEvent {
+                        physical_key: PhysicalKey::Code(KeyCode::Space | KeyCode::ArrowUp),
+                        state: ElementState::Pressed,
+                        repeat: false,
+                        ..
+                    },
+                ..
+            } => *jump_flag.borrow_mut() = true,
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button: MouseButton::Left,
+                ..
+            } => *jump_flag.borrow_mut() = true,
+            WindowEvent::Touch(Touch {
+                phase: TouchPhase::Started,
+                ..
+            }) => *jump_flag.borrow_mut() = true,
+            WindowEvent::RedrawRequested => {
+                let now = Instant::now();
+                let dt = now.duration_since(last_time).as_secs_f32();
+                last_time = now;
+                if let Err(err) = state.frame(dt) {
+                    error!("Frame error: {err:#}");
+                    target.exit();
+                }
+            }
+            _ => {}
+        },
+        Event::AboutToWait => window.request_redraw(),
+        _ => {}
+    })?;
+
+    Ok(())
+}
+
 #[derive(Default)]
 struct FrameTimer {
-    accumulator: f32,
     fps_accum: f32,
     fps_frames: u32,
     fps: f32,
 }
 
 impl FrameTimer {
+    /// Fixed-step ticking now lives in [`game::SimClock`]; this just tracks
+    /// the rolling FPS shown in the HUD.
     fn accumulate(&mut self, dt: f32) {
         if !dt.is_finite() {
             return;
         }
-        self.accumulator += dt;
         self.fps_accum += dt;
         self.fps_frames += 1;
-        let max_accum = STEP * 5.0;
-        if self.accumulator > max_accum {
-            self.accumulator = max_accum;
-        }
         if self.fps_accum >= 0.5 {
             self.fps = self.fps_frames as f32 / self.fps_accum.max(1e-5);
             self.fps_accum = 0.0;
@@ -390,9 +1020,12 @@ impl FrameTimer {
         }
     }
 
-    fn update_hud(&self, hud: &HtmlDivElement, score: i32, dead: bool) {
+    fn update_hud(&self, hud: &Hud, backend: &str, score: i32, dead: bool) {
         let status = if dead { " (DEAD)" } else { "" };
-        hud.set_inner_text(&format!("FPS: {:>5.1}\nScore: {}{}", self.fps, score, status));
+        hud.set_text(&format!(
+            "FPS: {:>5.1}\nScore: {}{}\n{}",
+            self.fps, score, status, backend
+        ));
     }
 }
 
@@ -402,6 +1035,8 @@ struct InstanceData {
     position: [f32; 2],
     size: [f32; 2],
     color: [f32; 4],
+    uv_offset: [f32; 2],
+    uv_scale: [f32; 2],
 }
 
 impl InstanceData {
@@ -426,9 +1061,268 @@ impl InstanceData {
                     offset: 16,
                     shader_location: 3,
                 },
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x2,
+                    offset: 32,
+                    shader_location: 4,
+                },
+                wgpu::VertexAttribute {
+                    format: wgpu::VertexFormat::Float32x2,
+                    offset: 40,
+                    shader_location: 5,
+                },
+            ],
+        }
+    }
+}
+
+/// Atlas sub-rectangles as `[uv_offset_x, uv_offset_y, uv_scale_x, uv_scale_y]`.
+/// The atlas (`atlas.png`) is a 2×2 grid; the bottom-right cell is solid white
+/// so color-only quads tint through unchanged.
+const UV_BIRD: [f32; 4] = [0.0, 0.0, 0.5, 0.5];
+const UV_PIPE: [f32; 4] = [0.5, 0.0, 0.5, 0.5];
+const UV_GROUND: [f32; 4] = [0.0, 0.5, 0.5, 0.5];
+const UV_WHITE: [f32; 4] = [0.5, 0.5, 0.5, 0.5];
+
+/// Decode the PNG sprite atlas and upload it to the GPU, returning a view and
+/// sampler ready to bind. Follows the texture-loading recipe from the
+/// learn-wgpu texture tutorial.
+fn load_atlas(device: &wgpu::Device, queue: &wgpu::Queue) -> Result<(wgpu::TextureView, wgpu::Sampler)> {
+    let atlas_bytes = include_bytes!("atlas.png");
+    let image = image::load_from_memory(atlas_bytes)
+        .map_err(|e| anyhow!("Failed to decode atlas: {e}"))?
+        .to_rgba8();
+    let (width, height) = image.dimensions();
+    let size = wgpu::Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("sprite atlas"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    queue.write_texture(
+        wgpu::ImageCopyTexture {
+            texture: &texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        &image,
+        wgpu::ImageDataLayout {
+            offset: 0,
+            bytes_per_row: Some(4 * width),
+            rows_per_image: Some(height),
+        },
+        size,
+    );
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("atlas sampler"),
+        mag_filter: wgpu::FilterMode::Nearest,
+        min_filter: wgpu::FilterMode::Nearest,
+        ..Default::default()
+    });
+    Ok((view, sampler))
+}
+
+/// Offscreen HDR render format. The scene is drawn into an `Rgba16Float`
+/// target when HDR is enabled so gradients and glow don't band on the 8-bit
+/// surface, then tonemapped down in a second pass.
+const HDR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// Whether the `?hdr=1` query flag requested the HDR render path. Native
+/// builds have no query string and default to off.
+#[cfg(target_arch = "wasm32")]
+fn hdr_requested() -> bool {
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .map(|query| query.contains("hdr=1"))
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn hdr_requested() -> bool {
+    false
+}
+
+/// Whether the `?learn=1` query flag requested neuroevolution learning mode
+/// instead of a single player-controlled bird. Native builds have no query
+/// string and default to off.
+#[cfg(target_arch = "wasm32")]
+fn learning_requested() -> bool {
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .map(|query| query.contains("learn=1"))
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn learning_requested() -> bool {
+    false
+}
+
+/// Whether the `?autopilot=1` query flag requested a trained autopilot fly
+/// the game instead of the player. Native builds have no query string and
+/// default to off.
+#[cfg(target_arch = "wasm32")]
+fn autopilot_requested() -> bool {
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .map(|query| query.contains("autopilot=1"))
+        .unwrap_or(false)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn autopilot_requested() -> bool {
+    false
+}
+
+/// Whether to draw the best-bird network overlay in learning mode. On by
+/// default; `?overlay=0` turns it off for an unobstructed view of the
+/// population density cloud. Native builds have no query string and always
+/// show it.
+#[cfg(target_arch = "wasm32")]
+fn overlay_requested() -> bool {
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .map(|query| !query.contains("overlay=0"))
+        .unwrap_or(true)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn overlay_requested() -> bool {
+    true
+}
+
+/// Fullscreen tonemapping post-pass and its offscreen HDR target. The target
+/// is lazily (re)created to match the surface size in [`Tonemap::ensure`].
+struct Tonemap {
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    pipeline: wgpu::RenderPipeline,
+    view: Option<wgpu::TextureView>,
+    bind_group: Option<wgpu::BindGroup>,
+    size: (u32, u32),
+}
+
+impl Tonemap {
+    fn new(device: &wgpu::Device, surface_format: wgpu::TextureFormat) -> Self {
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("tonemap bind group layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
             ],
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("tonemap sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let shader = device.create_shader_module(wgpu::include_wgsl!("shaders/tonemap.wgsl"));
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("tonemap pipeline layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("tonemap pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: surface_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        Self {
+            layout,
+            sampler,
+            pipeline,
+            view: None,
+            bind_group: None,
+            size: (0, 0),
         }
     }
+
+    /// Recreate the offscreen HDR texture when the surface size changes.
+    fn ensure(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        if self.view.is_some() && self.size == (width, height) {
+            return;
+        }
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("hdr target"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("tonemap bind group"),
+            layout: &self.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        });
+        self.view = Some(view);
+        self.bind_group = Some(bind_group);
+        self.size = (width, height);
+    }
 }
 
 struct Renderer {
@@ -438,10 +1332,11 @@ struct Renderer {
     bind_group: wgpu::BindGroup,
     pipeline: wgpu::RenderPipeline,
     uniform_buffer: wgpu::Buffer,
+    tonemap: Option<Tonemap>,
 }
 
 impl Renderer {
-    fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Result<Self> {
+    fn new(device: &wgpu::Device, queue: &wgpu::Queue, format: wgpu::TextureFormat) -> Result<Self> {
         let vertices: [[f32; 2]; 6] = [
             [0.0, 0.0],
             [1.0, 0.0],
@@ -471,29 +1366,62 @@ impl Renderer {
             mapped_at_creation: false,
         });
 
+        let (atlas_view, atlas_sampler) = load_atlas(device, queue)?;
+
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("bind group layout"),
-            entries: &[wgpu::BindGroupLayoutEntry {
-                binding: 0,
-                visibility: wgpu::ShaderStages::VERTEX,
-                ty: wgpu::BindingType::Buffer {
-                    ty: wgpu::BufferBindingType::Uniform,
-                    has_dynamic_offset: false,
-                    min_binding_size: None,
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
                 },
-                count: None,
-            }],
+            ],
         });
 
         let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("bind group"),
             layout: &bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: uniform_buffer.as_entire_binding(),
-            }],
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&atlas_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&atlas_sampler),
+                },
+            ],
         });
 
+        let tonemap = hdr_requested().then(|| Tonemap::new(device, format));
+        let scene_format = if tonemap.is_some() { HDR_FORMAT } else { format };
+
         let shader = device.create_shader_module(wgpu::include_wgsl!("shaders/quad.wgsl"));
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -526,7 +1454,7 @@ impl Renderer {
                 module: &shader,
                 entry_point: "fs_main",
                 targets: &[Some(wgpu::ColorTargetState {
-                    format,
+                    format: scene_format,
                     blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
@@ -553,6 +1481,7 @@ impl Renderer {
             bind_group,
             pipeline,
             uniform_buffer,
+            tonemap,
         })
     }
 
@@ -587,6 +1516,10 @@ impl Renderer {
         let screen = [config.width as f32, config.height as f32];
         queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&screen));
 
+        if let Some(tonemap) = &mut self.tonemap {
+            tonemap.ensure(device, config.width, config.height);
+        }
+
         let frame = surface.get_current_texture()?;
         let view = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
 
@@ -594,11 +1527,19 @@ impl Renderer {
             label: Some("render encoder"),
         });
 
+        // When HDR is enabled the scene renders into the offscreen float target
+        // and is tonemapped to the surface below; otherwise it draws straight
+        // to the surface view.
+        let scene_view = match &self.tonemap {
+            Some(tonemap) => tonemap.view.as_ref().expect("hdr target ensured above"),
+            None => &view,
+        };
+
         {
             let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("render pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
+                    view: scene_view,
                     resolve_target: None,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color {
@@ -621,11 +1562,110 @@ impl Renderer {
             pass.draw(0..6, 0..instances.len() as u32);
         }
 
-        queue.submit(Some(encoder.finish()));
-        frame.present();
-        Ok(())
-    }
-}
+        if let Some(tonemap) = &self.tonemap {
+            let bind_group = tonemap
+                .bind_group
+                .as_ref()
+                .expect("hdr target ensured above");
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("tonemap pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&tonemap.pipeline);
+            pass.set_bind_group(0, bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+
+        queue.submit(Some(encoder.finish()));
+        frame.present();
+        Ok(())
+    }
+}
+
+// Parallax background. Each layer tiles horizontally at its own fraction of
+// the world scroll speed so nearer layers appear to move faster, giving the
+// flat scene depth without a new render pipeline.
+
+/// One parallax background layer: how fast it scrolls relative to the
+/// world's scroll speed, the width of one repeating tile, where it sits on
+/// screen, and the solid color it's drawn in.
+#[derive(Clone, Copy)]
+struct BackgroundLayer {
+    scroll_factor: f32,
+    tile: f32,
+    y: f32,
+    size: [f32; 2],
+    color: [f32; 4],
+}
+
+/// The ordered stack of parallax layers behind the pipes and ground,
+/// farthest first. Swapping this out (e.g. for a themed variant) restyles
+/// the whole background without touching `Camera` or the render loop.
+#[derive(Clone)]
+struct Palette {
+    layers: Vec<BackgroundLayer>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        const HILL_TILE: f32 = 180.0;
+        const CLOUD_TILE: f32 = 140.0;
+        Self {
+            layers: vec![
+                // Far hill layer, scrolling slowest.
+                BackgroundLayer {
+                    scroll_factor: 0.25,
+                    tile: HILL_TILE,
+                    y: WORLD_HEIGHT - GROUND_HEIGHT - 70.0,
+                    size: [HILL_TILE * 0.5, 70.0],
+                    color: [0.42, 0.68, 0.38, 1.0],
+                },
+                // Nearer cloud layer, scrolling at half speed.
+                BackgroundLayer {
+                    scroll_factor: 0.5,
+                    tile: CLOUD_TILE,
+                    y: 60.0,
+                    size: [CLOUD_TILE * 0.4, 28.0],
+                    color: [1.0, 1.0, 1.0, 0.85],
+                },
+            ],
+        }
+    }
+}
+
+/// Per-layer horizontal scroll offsets for the parallax background, plus the
+/// ground's own offset. Advanced from inside the fixed `STEP` loop so motion
+/// stays deterministic with the physics. `layer_offsets` is resized to match
+/// the active `Palette` the first time it is advanced.
+#[derive(Default, Clone)]
+struct Camera {
+    layer_offsets: Vec<f32>,
+    ground_offset: f32,
+}
+
+impl Camera {
+    fn advance(&mut self, dt: f32, scroll_speed: f32, palette: &Palette) {
+        if self.layer_offsets.len() != palette.layers.len() {
+            self.layer_offsets = vec![0.0; palette.layers.len()];
+        }
+        for (offset, layer) in self.layer_offsets.iter_mut().zip(&palette.layers) {
+            *offset = (*offset + scroll_speed * layer.scroll_factor * dt) % layer.tile;
+        }
+        self.ground_offset = (self.ground_offset + scroll_speed * dt) % GROUND_TILE;
+    }
+}
+
+const GROUND_TILE: f32 = 48.0;
 
 #[derive(Clone, Copy)]
 struct PipePair {
@@ -634,6 +1674,70 @@ struct PipePair {
     passed: bool,
 }
 
+/// A recorded run replayed alongside a live attempt. It carries only the flap
+/// ticks; the pipe stream comes from the shared seed, so the ghost re-derives
+/// the exact same trajectory it flew originally.
+struct Ghost {
+    y: f32,
+    v: f32,
+    flaps: Vec<u32>,
+    cursor: usize,
+    dead: bool,
+}
+
+impl Ghost {
+    fn new(flaps: Vec<u32>) -> Self {
+        Self {
+            y: WORLD_HEIGHT / 2.0,
+            v: 0.0,
+            flaps,
+            cursor: 0,
+            dead: false,
+        }
+    }
+
+    /// Restart the playback from the first recorded flap, for when the live
+    /// attempt restarts against the same seed.
+    fn rewind(&mut self) {
+        self.y = WORLD_HEIGHT / 2.0;
+        self.v = 0.0;
+        self.cursor = 0;
+        self.dead = false;
+    }
+
+    /// Advance one fixed tick, flapping on the ticks in the recording and
+    /// applying the same gravity, bounds, and pipe collisions as the player.
+    /// `gravity`/`flap_velocity` are passed in rather than read from the
+    /// module constants so the ghost stays in lockstep with the live run even
+    /// when a tuning script overrides them.
+    fn advance(&mut self, tick: u32, dt: f32, pipes: &[PipePair], gravity: f32, flap_velocity: f32) {
+        if self.dead {
+            return;
+        }
+        if self.cursor < self.flaps.len() && self.flaps[self.cursor] == tick {
+            self.v = flap_velocity.max(MAX_RISE_SPEED);
+            self.cursor += 1;
+        }
+        self.v = (self.v + gravity * dt).clamp(MAX_RISE_SPEED, MAX_FALL_SPEED);
+        self.y += self.v * dt;
+        if self.y < 0.0 || self.y + 12.0 >= WORLD_HEIGHT - GROUND_HEIGHT {
+            self.dead = true;
+            return;
+        }
+        let half_gap = PIPE_GAP / 2.0;
+        for pipe in pipes {
+            if BIRD_X + 17.0 > pipe.x && BIRD_X - 17.0 < pipe.x + PIPE_WIDTH {
+                if self.y - 12.0 < pipe.gap_center - half_gap
+                    || self.y + 12.0 > pipe.gap_center + half_gap
+                {
+                    self.dead = true;
+                    break;
+                }
+            }
+        }
+    }
+}
+
 struct Game {
     bird_y: f32,
     bird_v: f32,
@@ -641,10 +1745,43 @@ struct Game {
     pipes: Vec<PipePair>,
     spawn_timer: f32,
     score: i32,
+    camera: Camera,
+    /// Parallax layer definitions, swappable so a theme can supply its own
+    /// colors and speeds without touching `Camera` or the render loop.
+    palette: Palette,
+    // Neuroevolution "learning" mode. When `birds` is non-empty the whole
+    // population plays simultaneously against the single shared pipe stream in
+    // `pipes`, and the player fields above track the current best bird.
+    birds: Vec<Bird>,
+    generation: u32,
+    seed: u64,
+    rng: Rng,
+    // Record-and-replay. `tick` counts fixed steps of the current run and
+    // `flap_log` records the ticks the player flapped on, so a run can be
+    // reproduced exactly against the same seed. `ghost`, when present, replays
+    // a previous run alongside the live attempt.
+    tick: u32,
+    flap_log: Vec<u32>,
+    ghost: Option<Ghost>,
+    /// Difficulty knobs, optionally overridden by a Rhai script on the web
+    /// build. Defaults reproduce the original hardcoded constants.
+    tuning: tuning::Tuning,
 }
 
 impl Game {
+    /// Construct a live game seeded from real entropy, so every playthrough
+    /// gets a different pipe stream. Callers that need reproducibility
+    /// (learning generations, replays) should use [`Game::new_seeded`] with an
+    /// explicit seed instead.
     fn new() -> Self {
+        Self::new_seeded(entropy_seed())
+    }
+
+    /// Construct a game whose pipe stream is driven entirely by the given
+    /// seed, so identical seeds reproduce identical runs. This is what makes a
+    /// learning generation fair and replays exact, and it frees the core sim
+    /// from the JS runtime.
+    fn new_seeded(seed: u64) -> Self {
         let mut game = Self {
             bird_y: WORLD_HEIGHT / 2.0,
             bird_v: 0.0,
@@ -652,11 +1789,81 @@ impl Game {
             pipes: Vec::new(),
             spawn_timer: 0.0,
             score: 0,
+            camera: Camera::default(),
+            palette: Palette::default(),
+            birds: Vec::new(),
+            generation: 0,
+            seed,
+            rng: Rng::new(seed),
+            tick: 0,
+            flap_log: Vec::new(),
+            ghost: None,
+            tuning: tuning::Tuning::default(),
         };
         game.populate_initial_pipes();
         game
     }
 
+    /// Replace the difficulty tuning (e.g. loaded from a Rhai preset). Defaults
+    /// reproduce the original constants when no script is supplied.
+    fn set_tuning(&mut self, tuning: tuning::Tuning) {
+        self.tuning = tuning;
+    }
+
+    /// Replace the parallax background layers (e.g. for a themed variant).
+    /// Defaults reproduce the original hill/cloud look.
+    fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+    }
+
+    /// The current run's replay record: its seed plus the ticks the player
+    /// flapped on. Feed this back into [`Game::start_replay`] to reproduce it.
+    fn replay_record(&self) -> (u64, Vec<u32>) {
+        (self.seed, self.flap_log.clone())
+    }
+
+    /// Begin a fresh live attempt while a recorded run plays back as a ghost.
+    /// Both share the seed, so they experience identical pipes.
+    fn start_replay(&mut self, seed: u64, flaps: Vec<u32>) {
+        self.seed = seed;
+        self.reset();
+        self.ghost = Some(Ghost::new(flaps));
+    }
+
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Next pipe gap center, advancing the seeded generator once per spawn.
+    fn next_gap(&mut self) -> f32 {
+        let (min_y, max_y) = (self.tuning.pipe_min_gap, self.tuning.pipe_max_gap);
+        min_y + (max_y - min_y) * self.rng.next_f32()
+    }
+
+    /// Horizontal distance between consecutive pipe spawns. `0.0` in the
+    /// tuning means "use the built-in default".
+    fn pipe_spacing(&self) -> f32 {
+        if self.tuning.pipe_spacing > 0.0 {
+            self.tuning.pipe_spacing
+        } else {
+            PIPE_SPACING
+        }
+    }
+
+    /// Construct a game running in neuroevolution learning mode with a fresh
+    /// random population. All birds play the same pipe stream each generation.
+    fn new_learning(population_size: usize) -> Self {
+        let mut game = Self::new();
+        game.birds = (0..population_size)
+            .map(|_| Bird::new(NeuralNet::random(&NET_CONFIG)))
+            .collect();
+        game
+    }
+
+    fn learning(&self) -> bool {
+        !self.birds.is_empty()
+    }
+
     fn reset(&mut self) {
         self.bird_y = WORLD_HEIGHT / 2.0;
         self.bird_v = 0.0;
@@ -664,30 +1871,46 @@ impl Game {
         self.pipes.clear();
         self.spawn_timer = 0.0;
         self.score = 0;
+        self.rng = Rng::new(self.seed);
+        self.tick = 0;
+        self.flap_log.clear();
+        if let Some(ghost) = self.ghost.as_mut() {
+            ghost.rewind();
+        }
         self.populate_initial_pipes();
     }
 
     fn populate_initial_pipes(&mut self) {
+        let spacing = self.pipe_spacing();
         let mut x = WORLD_WIDTH + 40.0;
         for _ in 0..4 {
+            let gap_center = self.next_gap();
             self.pipes.push(PipePair {
                 x,
-                gap_center: random_gap(),
+                gap_center,
                 passed: false,
             });
-            x += PIPE_SPACING;
+            x += spacing;
         }
     }
 
     fn step(&mut self, dt: f32, want_jump: bool) {
+        self.camera.advance(dt, self.tuning.scroll_speed, &self.palette);
+
+        if self.learning() {
+            self.learning_step(dt);
+            return;
+        }
+
         if want_jump {
             if self.is_dead {
                 self.reset();
             }
-            self.bird_v = FLAP_VELOCITY.max(MAX_RISE_SPEED);
+            self.bird_v = self.tuning.flap_velocity.max(MAX_RISE_SPEED);
+            self.flap_log.push(self.tick);
         }
 
-        self.bird_v = (self.bird_v + GRAVITY * dt).clamp(MAX_RISE_SPEED, MAX_FALL_SPEED);
+        self.bird_v = (self.bird_v + self.tuning.gravity * dt).clamp(MAX_RISE_SPEED, MAX_FALL_SPEED);
         self.bird_y += self.bird_v * dt;
 
         if self.bird_y < 0.0 {
@@ -703,23 +1926,29 @@ impl Game {
             return;
         }
 
+        let spacing = self.pipe_spacing();
         self.spawn_timer += dt;
-        if self.spawn_timer >= PIPE_SPACING / PIPE_SPEED {
-            self.spawn_timer -= PIPE_SPACING / PIPE_SPEED;
+        if self.spawn_timer >= spacing / self.tuning.scroll_speed {
+            self.spawn_timer -= spacing / self.tuning.scroll_speed;
+            let gap_center = self.next_gap();
             self.pipes.push(PipePair {
                 x: WORLD_WIDTH + PIPE_WIDTH,
-                gap_center: random_gap(),
+                gap_center,
                 passed: false,
             });
         }
 
+        let prev_score = self.score;
         for pipe in &mut self.pipes {
-            pipe.x -= PIPE_SPEED * dt;
+            pipe.x -= self.tuning.scroll_speed * dt;
             if !pipe.passed && pipe.x + PIPE_WIDTH < BIRD_X {
                 pipe.passed = true;
                 self.score += 1;
             }
         }
+        if self.score > prev_score {
+            self.tuning.on_score(self.score as u32);
+        }
 
         self.pipes.retain(|pipe| pipe.x + PIPE_WIDTH > -80.0);
 
@@ -734,43 +1963,300 @@ impl Game {
                 }
             }
         }
+
+        // Drive the replay ghost, if any, from its recorded flaps against the
+        // same (shared-seed) pipe stream, then advance the run's tick counter.
+        if let Some(ghost) = self.ghost.as_mut() {
+            ghost.advance(self.tick, dt, &self.pipes, self.tuning.gravity, self.tuning.flap_velocity);
+        }
+        self.tick = self.tick.wrapping_add(1);
+    }
+
+    /// Advance one fixed tick of the neuroevolution population. All living
+    /// birds share the single pipe stream in `self.pipes`; when they are all
+    /// dead the next generation is bred and the world resets.
+    fn learning_step(&mut self, dt: f32) {
+        // Scroll the shared pipe stream.
+        let spacing = self.pipe_spacing();
+        self.spawn_timer += dt;
+        if self.spawn_timer >= spacing / self.tuning.scroll_speed {
+            self.spawn_timer -= spacing / self.tuning.scroll_speed;
+            let gap_center = self.next_gap();
+            self.pipes.push(PipePair {
+                x: WORLD_WIDTH + PIPE_WIDTH,
+                gap_center,
+                passed: false,
+            });
+        }
+        for pipe in &mut self.pipes {
+            pipe.x -= self.tuning.scroll_speed * dt;
+        }
+        self.pipes.retain(|pipe| pipe.x + PIPE_WIDTH > -80.0);
+
+        // A pipe passing BIRD_X happens at the same instant for every bird, so
+        // award the survival bonus to all living birds at once.
+        let mut passed_now = false;
+        for pipe in &mut self.pipes {
+            if !pipe.passed && pipe.x + PIPE_WIDTH < BIRD_X {
+                pipe.passed = true;
+                passed_now = true;
+                self.score += 1;
+            }
+        }
+
+        let next = next_pipe(&self.pipes);
+        let half_gap = PIPE_GAP / 2.0;
+        for bird in &mut self.birds {
+            if !bird.alive {
+                continue;
+            }
+            if let Some(pipe) = next {
+                let inputs = [
+                    bird.y / WORLD_HEIGHT,
+                    bird.v / MAX_FALL_SPEED,
+                    (pipe.x - BIRD_X) / WORLD_WIDTH,
+                    pipe.gap_center / WORLD_HEIGHT,
+                ];
+                if bird.net.forward(&inputs)[0] > 0.5 {
+                    bird.v = self.tuning.flap_velocity.max(MAX_RISE_SPEED);
+                }
+            }
+
+            bird.v = (bird.v + self.tuning.gravity * dt).clamp(MAX_RISE_SPEED, MAX_FALL_SPEED);
+            bird.y += bird.v * dt;
+            bird.fitness += 1.0;
+            if passed_now {
+                bird.fitness += PIPE_FITNESS_BONUS;
+            }
+
+            if bird.y < 0.0 || bird.y + 12.0 >= WORLD_HEIGHT - GROUND_HEIGHT {
+                bird.alive = false;
+                continue;
+            }
+            for pipe in &self.pipes {
+                if BIRD_X + 17.0 > pipe.x && BIRD_X - 17.0 < pipe.x + PIPE_WIDTH {
+                    if bird.y - 12.0 < pipe.gap_center - half_gap
+                        || bird.y + 12.0 > pipe.gap_center + half_gap
+                    {
+                        bird.alive = false;
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Mirror the current best bird onto the player fields for rendering.
+        if let Some(best) = self.best_bird() {
+            self.bird_y = best.y;
+            self.bird_v = best.v;
+        }
+
+        if self.birds.iter().all(|bird| !bird.alive) {
+            self.evolve();
+        }
+    }
+
+    fn best_bird(&self) -> Option<&Bird> {
+        self.birds
+            .iter()
+            .filter(|bird| bird.alive)
+            .max_by(|a, b| a.fitness.total_cmp(&b.fitness))
+            .or_else(|| {
+                self.birds
+                    .iter()
+                    .max_by(|a, b| a.fitness.total_cmp(&b.fitness))
+            })
+    }
+
+    /// Breed the next generation: keep the top ~25% as elites, then fill the
+    /// rest with crossover of two top performers followed by mutation.
+    fn evolve(&mut self) {
+        self.birds.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
+        let population = self.birds.len();
+        let elite_count = (population as f32 * ELITE_FRACTION).ceil() as usize;
+        let elite_count = elite_count.clamp(1, population);
+
+        let mut next = Vec::with_capacity(population);
+        for bird in self.birds.iter().take(elite_count) {
+            next.push(Bird::new(bird.net.clone()));
+        }
+        while next.len() < population {
+            let a = &self.birds[rand_index(elite_count)];
+            let b = &self.birds[rand_index(elite_count)];
+            let mut net = NeuralNet::crossover(&a.net, &b.net);
+            net.mutate();
+            next.push(Bird::new(net));
+        }
+
+        self.birds = next;
+        self.generation += 1;
+        self.reset_world();
+
+        #[cfg(target_arch = "wasm32")]
+        if let Some(store) = local_storage() {
+            let _ = store.set_item(BRAIN_STORAGE_KEY, &self.export_best_brain());
+        }
+    }
+
+    /// Reset the shared world (pipes and player-mirror fields) for a new
+    /// generation, leaving the population's brains intact.
+    fn reset_world(&mut self) {
+        self.bird_y = WORLD_HEIGHT / 2.0;
+        self.bird_v = 0.0;
+        self.is_dead = false;
+        self.pipes.clear();
+        self.spawn_timer = 0.0;
+        self.score = 0;
+        self.rng = Rng::new(self.seed);
+        self.populate_initial_pipes();
+    }
+
+    fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Export the current best bird's network as a JSON string, following the
+    /// `{ "config": [...], "weights": [[...], ...] }` shape so a champion can
+    /// be persisted (e.g. to `localStorage`) and reloaded later.
+    fn export_best_brain(&self) -> String {
+        match self.best_bird() {
+            Some(bird) => bird.net.to_json(),
+            None => String::from("{}"),
+        }
+    }
+
+    /// Seed a fresh learning run from a previously exported brain: the champion
+    /// itself plus mutated copies fill out the population.
+    fn load_brain(&mut self, json: &str) -> bool {
+        let Some(net) = NeuralNet::from_json(json) else {
+            return false;
+        };
+        let size = if self.birds.is_empty() {
+            POPULATION_SIZE
+        } else {
+            self.birds.len()
+        };
+        self.birds = (0..size)
+            .map(|i| {
+                let mut clone = net.clone();
+                if i != 0 {
+                    clone.mutate();
+                }
+                Bird::new(clone)
+            })
+            .collect();
+        self.generation = 0;
+        self.reset_world();
+        true
     }
 
     fn instance_data(&self, screen_w: u32, screen_h: u32) -> Vec<InstanceData> {
         let mut instances = Vec::with_capacity(16 + self.pipes.len() * 2);
         let (scale, offset) = compute_scale_and_offset(screen_w, screen_h);
 
+        // Parallax background, farthest layer first, each tiled to its own
+        // scroll offset so themed palettes can vary color, speed, and tile
+        // size per layer.
+        for (layer, &layer_offset) in self.palette.layers.iter().zip(&self.camera.layer_offsets) {
+            let mut x = -layer_offset;
+            while x < WORLD_WIDTH {
+                instances.push(rect([x, layer.y], layer.size, layer.color, scale, offset));
+                x += layer.tile;
+            }
+        }
+
         // Ground
-        instances.push(rect(
+        instances.push(sprite(
             [0.0, WORLD_HEIGHT - GROUND_HEIGHT],
             [WORLD_WIDTH, GROUND_HEIGHT],
             [0.85, 0.74, 0.45, 1.0],
+            UV_GROUND,
             scale,
             offset,
         ));
 
-        // Bird
-        let bird_color = if self.is_dead {
-            [0.7, 0.3, 0.3, 1.0]
+        // Scrolling ground stripes at full speed, to read the motion.
+        let ground_y = WORLD_HEIGHT - GROUND_HEIGHT;
+        let mut x = -self.camera.ground_offset;
+        while x < WORLD_WIDTH {
+            instances.push(rect(
+                [x, ground_y],
+                [GROUND_TILE * 0.5, 8.0],
+                [0.72, 0.60, 0.34, 1.0],
+                scale,
+                offset,
+            ));
+            x += GROUND_TILE;
+        }
+
+        // Bird(s). In learning mode the whole live population is drawn as a
+        // translucent density cloud with the current best bird opaque on top;
+        // in player mode it is the single bird.
+        if self.learning() {
+            for bird in &self.birds {
+                if !bird.alive {
+                    continue;
+                }
+                instances.push(sprite(
+                    [BIRD_X - 17.0, bird.y - 12.0],
+                    [34.0, 24.0],
+                    [1.0, 0.93, 0.0, 0.25],
+                    UV_BIRD,
+                    scale,
+                    offset,
+                ));
+            }
+            if let Some(best) = self.best_bird() {
+                instances.push(sprite(
+                    [BIRD_X - 17.0, best.y - 12.0],
+                    [34.0, 24.0],
+                    [1.0, 0.6, 0.0, 1.0],
+                    UV_BIRD,
+                    scale,
+                    offset,
+                ));
+                if overlay_requested() {
+                    push_network_overlay(&mut instances, &best.net, scale, offset);
+                }
+            }
         } else {
-            [1.0, 0.93, 0.0, 1.0]
-        };
-        instances.push(rect(
-            [BIRD_X - 17.0, self.bird_y - 12.0],
-            [34.0, 24.0],
-            bird_color,
-            scale,
-            offset,
-        ));
+            // Replay ghost drawn behind the live bird in a cool translucent tint.
+            if let Some(ghost) = self.ghost.as_ref() {
+                if !ghost.dead {
+                    instances.push(sprite(
+                        [BIRD_X - 17.0, ghost.y - 12.0],
+                        [34.0, 24.0],
+                        [0.4, 0.7, 1.0, 0.4],
+                        UV_BIRD,
+                        scale,
+                        offset,
+                    ));
+                }
+            }
+            let bird_color = if self.is_dead {
+                [0.7, 0.3, 0.3, 1.0]
+            } else {
+                [1.0, 0.93, 0.0, 1.0]
+            };
+            instances.push(sprite(
+                [BIRD_X - 17.0, self.bird_y - 12.0],
+                [34.0, 24.0],
+                bird_color,
+                UV_BIRD,
+                scale,
+                offset,
+            ));
+        }
 
         for pipe in &self.pipes {
             let half_gap = PIPE_GAP / 2.0;
             let top_h = (pipe.gap_center - half_gap).max(0.0);
             if top_h > 0.0 {
-                instances.push(rect(
+                instances.push(sprite(
                     [pipe.x, 0.0],
                     [PIPE_WIDTH, top_h],
                     [0.37, 0.82, 0.39, 1.0],
+                    UV_PIPE,
                     scale,
                     offset,
                 ));
@@ -778,10 +2264,11 @@ impl Game {
             let bottom_y = pipe.gap_center + half_gap;
             let bottom_h = (WORLD_HEIGHT - bottom_y - GROUND_HEIGHT).max(0.0);
             if bottom_h > 0.0 {
-                instances.push(rect(
+                instances.push(sprite(
                     [pipe.x, bottom_y],
                     [PIPE_WIDTH, bottom_h],
                     [0.37, 0.82, 0.39, 1.0],
+                    UV_PIPE,
                     scale,
                     offset,
                 ));
@@ -807,15 +2294,650 @@ fn rect(
     color: [f32; 4],
     scale: f32,
     offset: [f32; 2],
+) -> InstanceData {
+    sprite(position, size, color, UV_WHITE, scale, offset)
+}
+
+/// Append a small debug panel in the top-right of the play area that draws
+/// `net` as nodes (one square per neuron, laid out in a column per layer) and
+/// edges (squares sampled along each connection, tinted by weight sign and
+/// sized by magnitude). It is built in world space so it rides the shared
+/// scale/offset into a fixed on-screen corner, and turns the abstract weight
+/// vectors into something watchable as generations evolve.
+fn push_network_overlay(
+    instances: &mut Vec<InstanceData>,
+    net: &NeuralNet,
+    scale: f32,
+    offset: [f32; 2],
+) {
+    const PANEL_W: f32 = 180.0;
+    const PANEL_H: f32 = 120.0;
+    const MARGIN: f32 = 16.0;
+    const NODE: f32 = 9.0;
+    let origin = [WORLD_WIDTH - PANEL_W - MARGIN, MARGIN];
+
+    // Dim backing panel so the graph reads against the sky.
+    instances.push(rect(
+        origin,
+        [PANEL_W, PANEL_H],
+        [0.05, 0.07, 0.12, 0.55],
+        scale,
+        offset,
+    ));
+
+    let layers = net.config.len();
+    let node_center = |layer: usize, index: usize, count: usize| -> [f32; 2] {
+        let fx = if layers > 1 {
+            layer as f32 / (layers - 1) as f32
+        } else {
+            0.5
+        };
+        let fy = if count > 1 {
+            index as f32 / (count - 1) as f32
+        } else {
+            0.5
+        };
+        [
+            origin[0] + 12.0 + fx * (PANEL_W - 24.0),
+            origin[1] + 12.0 + fy * (PANEL_H - 24.0),
+        ]
+    };
+
+    // Edges first so the neuron squares sit on top of them.
+    for (layer, weights) in net.weights.iter().enumerate() {
+        let inputs_n = net.config[layer];
+        let outputs_n = net.config[layer + 1];
+        for o in 0..outputs_n {
+            let base = o * (inputs_n + 1);
+            let to = node_center(layer + 1, o, outputs_n);
+            for i in 0..inputs_n {
+                let w = weights[base + i];
+                let from = node_center(layer, i, inputs_n);
+                let color = if w >= 0.0 {
+                    [0.30, 0.80, 1.0, 0.9]
+                } else {
+                    [1.0, 0.40, 0.35, 0.9]
+                };
+                let thickness = (w.abs() * 2.5).clamp(1.0, 4.0);
+                const SAMPLES: usize = 8;
+                for s in 0..SAMPLES {
+                    let t = (s as f32 + 0.5) / SAMPLES as f32;
+                    let px = from[0] + (to[0] - from[0]) * t;
+                    let py = from[1] + (to[1] - from[1]) * t;
+                    instances.push(rect(
+                        [px - thickness * 0.5, py - thickness * 0.5],
+                        [thickness, thickness],
+                        color,
+                        scale,
+                        offset,
+                    ));
+                }
+            }
+        }
+    }
+
+    // Neurons.
+    for layer in 0..layers {
+        let count = net.config[layer];
+        for index in 0..count {
+            let c = node_center(layer, index, count);
+            instances.push(rect(
+                [c[0] - NODE * 0.5, c[1] - NODE * 0.5],
+                [NODE, NODE],
+                [0.95, 0.95, 0.85, 1.0],
+                scale,
+                offset,
+            ));
+        }
+    }
+}
+
+fn sprite(
+    position: [f32; 2],
+    size: [f32; 2],
+    color: [f32; 4],
+    uv: [f32; 4],
+    scale: f32,
+    offset: [f32; 2],
 ) -> InstanceData {
     InstanceData {
         position: [position[0] * scale + offset[0], position[1] * scale + offset[1]],
         size: [size[0] * scale, size[1] * scale],
         color,
+        uv_offset: [uv[0], uv[1]],
+        uv_scale: [uv[2], uv[3]],
+    }
+}
+
+/// Fallback seed if no entropy source is available; also folded into every
+/// seed by [`Rng::new`] so a caller-supplied `0` still escapes the all-zero
+/// xorshift state.
+const DEFAULT_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A fresh seed for [`Game::new`] drawn from wall-clock entropy, so each
+/// playthrough gets a different pipe stream. `Game::new_seeded` is used
+/// instead wherever reproducibility matters (learning generations, replays).
+#[cfg(target_arch = "wasm32")]
+fn entropy_seed() -> u64 {
+    let millis = js_sys::Date::now().to_bits();
+    let perf = web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|perf| perf.now())
+        .unwrap_or(0.0)
+        .to_bits();
+    millis ^ perf.rotate_left(32)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn entropy_seed() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or(DEFAULT_SEED)
+}
+
+/// Small deterministic xorshift64 generator. Replaces `js_sys::Math::random`
+/// so pipe generation is reproducible and independent of the JS host.
+#[derive(Clone, Copy)]
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Avoid the all-zero state, which xorshift cannot escape.
+        Self {
+            state: (seed ^ DEFAULT_SEED) | 1,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Next value in `[0, 1)` using the top 24 bits for full f32 precision.
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+// Neuroevolution tuning. A small feed-forward net decides each bird's flap;
+// generations are bred by elitism + crossover + mutation.
+const NET_CONFIG: [usize; 3] = [4, 6, 1];
+const POPULATION_SIZE: usize = 150;
+const ELITE_FRACTION: f32 = 0.25;
+const MUTATION_RATE: f32 = 0.1;
+const MUTATION_SIGMA: f32 = 0.5;
+const PIPE_FITNESS_BONUS: f32 = 100.0;
+
+/// The leading un-passed pipe — the one a bird needs to clear next.
+fn next_pipe(pipes: &[PipePair]) -> Option<PipePair> {
+    pipes
+        .iter()
+        .filter(|pipe| pipe.x + PIPE_WIDTH >= BIRD_X)
+        .min_by(|a, b| a.x.total_cmp(&b.x))
+        .copied()
+}
+
+// Headless autopilot training. Unlike the in-game learning mode above (which
+// renders a whole population playing simultaneously), this breeds one genome
+// at a time offline against `Game::step`, replaying the same seed for every
+// genome in a generation so fitness is comparable, then hands the fittest to
+// `Autopilot::decide` to fly the live game.
+const AUTOPILOT_NET_CONFIG: [usize; 3] = [5, 6, 1];
+const AUTOPILOT_POPULATION: usize = 60;
+const AUTOPILOT_GENERATIONS: u32 = 25;
+const AUTOPILOT_ELITE_FRACTION: f32 = 0.25;
+const AUTOPILOT_MUTATION_RATE: f32 = 0.05;
+const AUTOPILOT_MUTATION_SIGMA: f32 = 0.2;
+/// Per-genome evaluation cap, so a genome that never dies still yields a
+/// finite generation. Training runs once, synchronously, before the first
+/// frame, so this also bounds how long the page hangs on startup.
+const AUTOPILOT_MAX_TICKS: u32 = 1800;
+const AUTOPILOT_SCORE_BONUS: f32 = 100.0;
+
+/// A single evolved genome that can fly the live game. Only ever reads
+/// `Game` state; it never mutates it.
+struct Autopilot {
+    net: NeuralNet,
+}
+
+impl Autopilot {
+    /// Evolve a population of genomes headlessly, every genome playing a
+    /// fresh `Game::new_seeded(seed)` so a generation's fitnesses are
+    /// comparable, and return an autopilot wrapping the fittest genome seen
+    /// across all generations.
+    fn train(seed: u64) -> Autopilot {
+        Self::train_with(seed, AUTOPILOT_POPULATION, AUTOPILOT_GENERATIONS)
+    }
+
+    /// As [`Autopilot::train`], but with an explicit population size and
+    /// generation count, so tests can evolve a tiny population instead of
+    /// paying for the full run.
+    fn train_with(seed: u64, population_size: usize, generations: u32) -> Autopilot {
+        let mut population: Vec<NeuralNet> = (0..population_size)
+            .map(|_| NeuralNet::random(&AUTOPILOT_NET_CONFIG))
+            .collect();
+        let mut champion = population[0].clone();
+        let mut champion_fitness = f32::MIN;
+
+        for _ in 0..generations {
+            let mut scored: Vec<(f32, NeuralNet)> = population
+                .into_iter()
+                .map(|net| {
+                    let fitness = Self::evaluate(&net, seed);
+                    (fitness, net)
+                })
+                .collect();
+            scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+            if scored[0].0 > champion_fitness {
+                champion_fitness = scored[0].0;
+                champion = scored[0].1.clone();
+            }
+
+            let elite_count =
+                ((scored.len() as f32 * AUTOPILOT_ELITE_FRACTION).ceil() as usize).clamp(1, scored.len());
+            let elites: Vec<NeuralNet> = scored.into_iter().take(elite_count).map(|(_, net)| net).collect();
+
+            let mut next = Vec::with_capacity(population_size);
+            next.extend(elites.iter().cloned());
+            while next.len() < population_size {
+                let a = &elites[rand_index(elites.len())];
+                let b = &elites[rand_index(elites.len())];
+                let mut child = NeuralNet::single_point_crossover(a, b);
+                child.mutate_with(AUTOPILOT_MUTATION_RATE, AUTOPILOT_MUTATION_SIGMA);
+                next.push(child);
+            }
+            population = next;
+        }
+
+        Autopilot { net: champion }
+    }
+
+    /// Run one genome on a fresh, fixed-seed game for up to
+    /// `AUTOPILOT_MAX_TICKS`, returning ticks-survived plus a bonus per pipe
+    /// scored.
+    fn evaluate(net: &NeuralNet, seed: u64) -> f32 {
+        let pilot = Autopilot { net: net.clone() };
+        let mut game = Game::new_seeded(seed);
+        let mut ticks = 0;
+        while ticks < AUTOPILOT_MAX_TICKS {
+            if game.is_dead {
+                break;
+            }
+            let want_jump = pilot.decide(&game);
+            game.step(STEP, want_jump);
+            ticks += 1;
+        }
+        ticks as f32 + game.score as f32 * AUTOPILOT_SCORE_BONUS
+    }
+
+    /// Decide whether to flap this frame, based only on the live game's
+    /// current state.
+    fn decide(&self, game: &Game) -> bool {
+        let Some(pipe) = next_pipe(&game.pipes) else {
+            return false;
+        };
+        let half_gap = PIPE_GAP / 2.0;
+        let inputs = [
+            game.bird_y / WORLD_HEIGHT,
+            game.bird_v / MAX_FALL_SPEED,
+            (pipe.x - BIRD_X) / WORLD_WIDTH,
+            (pipe.gap_center - half_gap - game.bird_y) / WORLD_HEIGHT,
+            (pipe.gap_center + half_gap - game.bird_y) / WORLD_HEIGHT,
+        ];
+        self.net.forward(&inputs)[0] > 0.5
+    }
+}
+
+/// One bird in the learning population: its physics state, whether it is still
+/// alive this generation, its accumulated fitness, and the brain driving it.
+struct Bird {
+    y: f32,
+    v: f32,
+    alive: bool,
+    fitness: f32,
+    net: NeuralNet,
+}
+
+impl Bird {
+    fn new(net: NeuralNet) -> Self {
+        Self {
+            y: WORLD_HEIGHT / 2.0,
+            v: 0.0,
+            alive: true,
+            fitness: 0.0,
+            net,
+        }
+    }
+}
+
+/// A tiny fully-connected feed-forward network: one `tanh` hidden layer and a
+/// `sigmoid` output. Weights are stored as one flat `Vec<f32>` per layer
+/// (row-major, a trailing bias per output neuron) so they serialize trivially.
+#[derive(Clone)]
+struct NeuralNet {
+    config: Vec<usize>,
+    weights: Vec<Vec<f32>>,
+}
+
+impl NeuralNet {
+    fn random(config: &[usize]) -> Self {
+        let config = config.to_vec();
+        let weights = config
+            .windows(2)
+            .map(|pair| {
+                let (inputs, outputs) = (pair[0], pair[1]);
+                (0..outputs * (inputs + 1)).map(|_| rand_weight()).collect()
+            })
+            .collect();
+        Self { config, weights }
+    }
+
+    /// Evaluate the network, returning the output layer activations.
+    fn forward(&self, inputs: &[f32]) -> Vec<f32> {
+        let mut activations = inputs.to_vec();
+        let last = self.weights.len() - 1;
+        for (layer, weights) in self.weights.iter().enumerate() {
+            let inputs_n = self.config[layer];
+            let outputs_n = self.config[layer + 1];
+            let mut next = vec![0.0; outputs_n];
+            for o in 0..outputs_n {
+                let base = o * (inputs_n + 1);
+                let mut sum = weights[base + inputs_n]; // bias
+                for i in 0..inputs_n {
+                    sum += weights[base + i] * activations[i];
+                }
+                next[o] = if layer == last {
+                    sigmoid(sum)
+                } else {
+                    sum.tanh()
+                };
+            }
+            activations = next;
+        }
+        activations
+    }
+
+    /// Breed a child by picking each weight from one of the two parents.
+    fn crossover(a: &NeuralNet, b: &NeuralNet) -> NeuralNet {
+        let weights = a
+            .weights
+            .iter()
+            .zip(&b.weights)
+            .map(|(wa, wb)| {
+                wa.iter()
+                    .zip(wb)
+                    .map(|(&x, &y)| if rand_unit() < 0.5 { x } else { y })
+                    .collect()
+            })
+            .collect();
+        NeuralNet {
+            config: a.config.clone(),
+            weights,
+        }
+    }
+
+    /// Perturb weights in place: with probability `MUTATION_RATE` add Gaussian
+    /// noise `N(0, MUTATION_SIGMA)` to a weight.
+    fn mutate(&mut self) {
+        self.mutate_with(MUTATION_RATE, MUTATION_SIGMA);
+    }
+
+    /// Breed a child by picking, per layer, a single split point and taking
+    /// weights from `a` up to it and from `b` after it. Used by the headless
+    /// autopilot trainer, which (per its spec) breeds this way rather than
+    /// the in-game learning mode's per-weight coin flip.
+    fn single_point_crossover(a: &NeuralNet, b: &NeuralNet) -> NeuralNet {
+        let weights = a
+            .weights
+            .iter()
+            .zip(&b.weights)
+            .map(|(wa, wb)| {
+                let split = rand_index(wa.len() + 1);
+                wa[..split].iter().chain(&wb[split..]).copied().collect()
+            })
+            .collect();
+        NeuralNet {
+            config: a.config.clone(),
+            weights,
+        }
+    }
+
+    /// Perturb weights in place: with probability `rate` add Gaussian noise
+    /// `N(0, sigma)` to a weight.
+    fn mutate_with(&mut self, rate: f32, sigma: f32) {
+        for layer in &mut self.weights {
+            for weight in layer {
+                if rand_unit() < rate {
+                    *weight += gaussian() * sigma;
+                }
+            }
+        }
+    }
+
+    /// Serialize to `{ "config": [...], "weights": [[...], ...] }`.
+    fn to_json(&self) -> String {
+        let config = self
+            .config
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        let weights = self
+            .weights
+            .iter()
+            .map(|layer| {
+                let values = layer
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!("[{values}]")
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("{{\"config\":[{config}],\"weights\":[{weights}]}}")
+    }
+
+    /// Parse the shape produced by [`NeuralNet::to_json`]. Returns `None` if the
+    /// JSON is malformed or the dimensions are inconsistent.
+    fn from_json(json: &str) -> Option<NeuralNet> {
+        let config = parse_number_array(json, "\"config\"")?
+            .into_iter()
+            .map(|n| n as usize)
+            .collect::<Vec<_>>();
+        let weights = parse_nested_array(json, "\"weights\"")?;
+        if config.len() < 2 || weights.len() != config.len() - 1 {
+            return None;
+        }
+        for (layer, pair) in weights.iter().zip(config.windows(2)) {
+            if layer.len() != pair[1] * (pair[0] + 1) {
+                return None;
+            }
+        }
+        Some(NeuralNet { config, weights })
+    }
+}
+
+/// Extract the flat number array that follows `key` (e.g. `"config"`).
+fn parse_number_array(json: &str, key: &str) -> Option<Vec<f32>> {
+    let start = json.find(key)? + key.len();
+    let open = json[start..].find('[')? + start + 1;
+    let close = json[open..].find(']')? + open;
+    parse_floats(&json[open..close])
+}
+
+/// Extract the array-of-arrays that follows `key` (e.g. `"weights"`).
+fn parse_nested_array(json: &str, key: &str) -> Option<Vec<Vec<f32>>> {
+    let start = json.find(key)? + key.len();
+    let open = json[start..].find('[')? + start + 1;
+    let mut layers = Vec::new();
+    let bytes = json.as_bytes();
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b']' => break,
+            b'[' => {
+                let inner_close = json[i + 1..].find(']')? + i + 1;
+                layers.push(parse_floats(&json[i + 1..inner_close])?);
+                i = inner_close + 1;
+            }
+            _ => i += 1,
+        }
+    }
+    Some(layers)
+}
+
+fn parse_floats(body: &str) -> Option<Vec<f32>> {
+    body.split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| token.parse::<f32>().ok())
+        .collect()
+}
+
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Uniform value in `[0, 1)`, used only for network weight initialization
+/// and mutation (the pipe stream uses the seeded [`Rng`] instead). On the web
+/// this comes straight from the JS host; natively there is no such host, so
+/// it falls back to a thread-local [`Rng`] seeded from [`entropy_seed`].
+#[cfg(target_arch = "wasm32")]
+fn rand_unit() -> f32 {
+    js_sys::Math::random() as f32
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn rand_unit() -> f32 {
+    use std::cell::RefCell;
+    thread_local! {
+        static RNG: RefCell<Rng> = RefCell::new(Rng::new(entropy_seed()));
     }
+    RNG.with(|rng| rng.borrow_mut().next_f32())
 }
 
-fn random_gap() -> f32 {
-    let seed = js_sys::Math::random();
-    PIPE_MIN_Y + (PIPE_MAX_Y - PIPE_MIN_Y) * seed as f32
+/// Initial weight in roughly `[-1, 1]`.
+fn rand_weight() -> f32 {
+    rand_unit() * 2.0 - 1.0
+}
+
+/// Standard-normal sample via the Box–Muller transform.
+fn gaussian() -> f32 {
+    let u1 = rand_unit().max(f32::MIN_POSITIVE);
+    let u2 = rand_unit();
+    (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+}
+
+/// Random index into `[0, len)`.
+fn rand_index(len: usize) -> usize {
+    ((rand_unit() * len as f32) as usize).min(len.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod rng_tests {
+    use super::Rng;
+
+    #[test]
+    fn same_seed_reproduces_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..16 {
+            assert_eq!(a.next_f32(), b.next_f32());
+        }
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        let mut a = Rng::new(1);
+        let mut b = Rng::new(2);
+        let sequence_a: Vec<f32> = (0..8).map(|_| a.next_f32()).collect();
+        let sequence_b: Vec<f32> = (0..8).map(|_| b.next_f32()).collect();
+        assert_ne!(sequence_a, sequence_b);
+    }
+
+    #[test]
+    fn values_stay_in_unit_range() {
+        let mut rng = Rng::new(0xDEAD_BEEF);
+        for _ in 0..64 {
+            let value = rng.next_f32();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod replay_tests {
+    use super::Game;
+
+    /// Play `seed` for 300 ticks flapping on a fixed schedule, returning the
+    /// per-tick pipe gap centers plus the final score/death state — exactly
+    /// what a replay needs to reproduce bit-for-bit.
+    fn run(seed: u64) -> (i32, bool, Vec<f32>) {
+        let flap_ticks = [0u32, 40, 80, 140, 200];
+        let mut game = Game::new_seeded(seed);
+        let mut gap_centers = Vec::new();
+        for tick in 0..300u32 {
+            game.step(STEP, flap_ticks.contains(&tick));
+            gap_centers.extend(game.pipes.iter().map(|pipe| pipe.gap_center));
+        }
+        (game.score, game.is_dead, gap_centers)
+    }
+
+    #[test]
+    fn identical_seed_and_inputs_reproduce_identical_runs() {
+        assert_eq!(run(7), run(7));
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        assert_ne!(run(1).2, run(2).2);
+    }
+}
+
+#[cfg(test)]
+mod autopilot_tests {
+    use super::{Autopilot, Game};
+
+    #[test]
+    fn decide_does_not_mutate_the_game() {
+        let mut game = Game::new_seeded(11);
+        for _ in 0..30 {
+            game.step(super::STEP, false);
+        }
+        let before = (game.bird_y, game.bird_v, game.score, game.is_dead, game.pipes.len());
+
+        let pilot = Autopilot::train_with(11, 8, 3);
+        let _ = pilot.decide(&game);
+
+        let after = (game.bird_y, game.bird_v, game.score, game.is_dead, game.pipes.len());
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn trained_autopilot_outlives_a_genome_that_never_flaps() {
+        let pilot = Autopilot::train_with(11, 12, 5);
+        let mut game = Game::new_seeded(11);
+        let mut ticks = 0;
+        while !game.is_dead && ticks < super::AUTOPILOT_MAX_TICKS {
+            let flap = pilot.decide(&game);
+            game.step(super::STEP, flap);
+            ticks += 1;
+        }
+        let mut never_flaps = Game::new_seeded(11);
+        let mut baseline_ticks = 0;
+        while !never_flaps.is_dead && baseline_ticks < super::AUTOPILOT_MAX_TICKS {
+            never_flaps.step(super::STEP, false);
+            baseline_ticks += 1;
+        }
+        assert!(ticks >= baseline_ticks);
+    }
 }
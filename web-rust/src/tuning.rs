@@ -0,0 +1,107 @@
+use rhai::{Engine, Map, Scope, AST};
+
+/// Tunable difficulty parameters. Defaults match the original hardcoded
+/// constants, so a game with no script supplied plays exactly as before.
+/// A script can override any field and, optionally, define a `ramp(score)`
+/// function that tightens the gap or speeds up scrolling as the score rises.
+pub struct Tuning {
+    pub gravity: f32,
+    pub flap_velocity: f32,
+    /// Lower bound of the vertical range a pipe's gap centre may spawn in.
+    pub pipe_min_gap: f32,
+    /// Upper bound of the vertical range a pipe's gap centre may spawn in.
+    pub pipe_max_gap: f32,
+    pub scroll_speed: f32,
+    /// Absolute pipe spacing; `0.0` keeps the built-in default.
+    pub pipe_spacing: f32,
+    ramp: Option<Ramp>,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        // These mirror the `Game`'s own module constants in lib.rs.
+        Self {
+            gravity: 900.0,
+            flap_velocity: -320.0,
+            pipe_min_gap: 160.0,
+            pipe_max_gap: 360.0,
+            scroll_speed: 120.0,
+            pipe_spacing: 0.0,
+            ramp: None,
+        }
+    }
+}
+
+impl Tuning {
+    /// Build a tuning from a Rhai script. The script evaluates to a map of
+    /// parameter overrides and may define a `ramp(score)` function returning a
+    /// map of per-score overrides. Returns the error text on a parse/eval
+    /// failure so the caller can fall back to the defaults.
+    pub fn from_script(src: &str) -> Result<Self, String> {
+        let engine = Engine::new();
+        let ast = engine.compile(src).map_err(|err| err.to_string())?;
+        let map: Map = engine.eval_ast(&ast).map_err(|err| err.to_string())?;
+
+        let mut tuning = Tuning::default();
+        tuning.read_map(&map);
+        if ast.iter_functions().any(|func| func.name == "ramp") {
+            tuning.ramp = Some(Ramp { engine, ast });
+        }
+        Ok(tuning)
+    }
+
+    /// Apply the script's `ramp(score)` overrides, if any, as the score rises.
+    pub fn on_score(&mut self, score: u32) {
+        if let Some(ramp) = self.ramp.take() {
+            ramp.apply(score, self);
+            self.ramp = Some(ramp);
+        }
+    }
+
+    fn read_map(&mut self, map: &Map) {
+        read_f32(map, "gravity", &mut self.gravity);
+        read_f32(map, "flap_velocity", &mut self.flap_velocity);
+        read_f32(map, "pipe_min_gap", &mut self.pipe_min_gap);
+        read_f32(map, "pipe_max_gap", &mut self.pipe_max_gap);
+        read_f32(map, "scroll_speed", &mut self.scroll_speed);
+        read_f32(map, "pipe_spacing", &mut self.pipe_spacing);
+    }
+}
+
+impl std::fmt::Debug for Tuning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tuning")
+            .field("gravity", &self.gravity)
+            .field("flap_velocity", &self.flap_velocity)
+            .field("pipe_min_gap", &self.pipe_min_gap)
+            .field("pipe_max_gap", &self.pipe_max_gap)
+            .field("scroll_speed", &self.scroll_speed)
+            .field("pipe_spacing", &self.pipe_spacing)
+            .field("scripted_ramp", &self.ramp.is_some())
+            .finish()
+    }
+}
+
+/// A compiled `ramp(score)` function kept around to re-tune as the score rises.
+struct Ramp {
+    engine: Engine,
+    ast: AST,
+}
+
+impl Ramp {
+    fn apply(&self, score: u32, tuning: &mut Tuning) {
+        let mut scope = Scope::new();
+        let result = self
+            .engine
+            .call_fn::<Map>(&mut scope, &self.ast, "ramp", (score as i64,));
+        if let Ok(map) = result {
+            tuning.read_map(&map);
+        }
+    }
+}
+
+fn read_f32(map: &Map, key: &str, slot: &mut f32) {
+    if let Some(value) = map.get(key).and_then(|dynamic| dynamic.as_float().ok()) {
+        *slot = value as f32;
+    }
+}